@@ -87,6 +87,19 @@ fn point_max<S: 'static + Float + Debug>(p: &[na::Point3<S>]) -> na::Point3<S> {
     )
 }
 
+/// Snap x to a multiple of spacing using round (floor or ceil). Quotients within a few ulps of an
+/// integer are taken as that integer, so snapping an already snapped value does not move it.
+fn snap<S: Float>(x: S, spacing: S, round: fn(S) -> S) -> S {
+    let q = x / spacing;
+    let nearest = q.round();
+    let tolerance = S::epsilon() * S::from(8.).unwrap() * Float::max(q.abs(), S::one());
+    if (q - nearest).abs() <= tolerance {
+        nearest * spacing
+    } else {
+        round(q) * spacing
+    }
+}
+
 impl<S: Float + Debug + na::RealField + simba::scalar::RealField> BoundingBox<S> {
     /// Returns an infinte sized box.
     pub fn infinity() -> BoundingBox<S> {
@@ -160,6 +173,22 @@ impl<S: Float + Debug + na::RealField + simba::scalar::RealField> BoundingBox<S>
         self.max.z += d;
         self
     }
+    /// Snap the corners of a Bounding Box to a global grid with the given spacing, rounding min
+    /// down and max up to a multiple of spacing. Boxes snapped with the same spacing share
+    /// lattice positions on their boundaries.
+    /// A corner within rounding error of a grid position is moved to that position's computed
+    /// value k * spacing, which may lie inside the original box by rounding error.
+    /// spacing must be positive.
+    pub fn snap_to_grid(&mut self, spacing: S) -> &mut Self {
+        debug_assert!(spacing > S::zero(), "grid spacing must be positive");
+        self.min.x = snap(self.min.x, spacing, Float::floor);
+        self.min.y = snap(self.min.y, spacing, Float::floor);
+        self.min.z = snap(self.min.z, spacing, Float::floor);
+        self.max.x = snap(self.max.x, spacing, Float::ceil);
+        self.max.y = snap(self.max.y, spacing, Float::ceil);
+        self.max.z = snap(self.max.z, spacing, Float::ceil);
+        self
+    }
     /// Add a Point to a Bounding Box, e.g. expand the Bounding Box to contain that point.
     pub fn insert(&mut self, o: &na::Point3<S>) -> &mut Self {
         self.min.x = Float::min(self.min.x, o.x);
@@ -194,6 +223,50 @@ impl<S: Float + Debug + na::RealField + simba::scalar::RealField> BoundingBox<S>
             && p.z >= self.min.z
             && p.z <= self.max.z
     }
+    /// Return the point of the Bounding Box closest to p, which is p itself if the Box contains p.
+    /// Returns None if the Box is empty (e.g. neg_infinity()), as it has no closest point.
+    pub fn closest_point(&self, p: &na::Point3<S>) -> Option<na::Point3<S>> {
+        if self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z {
+            return None;
+        }
+        Some(na::Point3::<S>::new(
+            Float::min(Float::max(p.x, self.min.x), self.max.x),
+            Float::min(Float::max(p.y, self.min.y), self.max.y),
+            Float::min(Float::max(p.z, self.min.z), self.max.z),
+        ))
+    }
+    /// Intersect the ray origin + t * dir with the Bounding Box.
+    /// Returns the ray parameters (t_enter, t_exit) at which the ray enters and leaves the box,
+    /// or None if the ray misses the box or the box lies behind the origin.
+    /// t_enter is negative if the origin is inside the box.
+    /// Faces count as part of the box, like in contains. An empty box is never hit. Axes along
+    /// which dir is zero do not limit the ray, so a zero dir returns (-inf, inf) if the box
+    /// contains origin and None otherwise.
+    pub fn intersect_ray(&self, origin: &na::Point3<S>, dir: &na::Vector3<S>) -> Option<(S, S)> {
+        let mut t_enter = S::neg_infinity();
+        let mut t_exit = S::infinity();
+        for i in 0..3 {
+            if self.min[i] > self.max[i] {
+                return None;
+            }
+            if dir[i] == S::zero() {
+                // The ray is parallel to this slab: it either stays within it or never enters.
+                if origin[i] < self.min[i] || origin[i] > self.max[i] {
+                    return None;
+                }
+                continue;
+            }
+            let t1 = (self.min[i] - origin[i]) / dir[i];
+            let t2 = (self.max[i] - origin[i]) / dir[i];
+            t_enter = Float::max(t_enter, Float::min(t1, t2));
+            t_exit = Float::min(t_exit, Float::max(t1, t2));
+        }
+        if t_exit >= Float::max(t_enter, S::zero()) {
+            Some((t_enter, t_exit))
+        } else {
+            None
+        }
+    }
 }
 
 impl<T: Float> AbsDiffEq for BoundingBox<T>
@@ -301,4 +374,156 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn intersect_ray() {
+        let bbox =
+            BoundingBox::<f64>::new(&na::Point3::new(0., 0., 0.), &na::Point3::new(1., 1., 1.));
+        let (t_enter, t_exit) = bbox
+            .intersect_ray(
+                &na::Point3::new(-1., 0.5, 0.5),
+                &na::Vector3::new(1., 0., 0.),
+            )
+            .unwrap();
+        assert_relative_eq!(t_enter, 1.);
+        assert_relative_eq!(t_exit, 2.);
+        let (t_enter, t_exit) = bbox
+            .intersect_ray(
+                &na::Point3::new(0.5, 0.5, 0.5),
+                &na::Vector3::new(0., 0., -2.),
+            )
+            .unwrap();
+        assert_relative_eq!(t_enter, -0.25);
+        assert_relative_eq!(t_exit, 0.25);
+        assert_eq!(
+            bbox.intersect_ray(
+                &na::Point3::new(2., 0.5, 0.5),
+                &na::Vector3::new(1., 0., 0.)
+            ),
+            None
+        );
+        assert_eq!(
+            bbox.intersect_ray(
+                &na::Point3::new(-1., 2., 0.5),
+                &na::Vector3::new(1., 0., 0.)
+            ),
+            None
+        );
+        let (t_enter, t_exit) = bbox
+            .intersect_ray(
+                &na::Point3::new(-1., 0., 0.5),
+                &na::Vector3::new(1., 0., 0.),
+            )
+            .unwrap();
+        assert_relative_eq!(t_enter, 1.);
+        assert_relative_eq!(t_exit, 2.);
+        let (t_enter, t_exit) = bbox
+            .intersect_ray(
+                &na::Point3::new(-1., 0., 0.5),
+                &na::Vector3::new(1., 1e-310, 0.),
+            )
+            .unwrap();
+        assert_relative_eq!(t_enter, 1.);
+        assert_relative_eq!(t_exit, 2.);
+        assert_eq!(
+            bbox.intersect_ray(
+                &na::Point3::new(0.5, 0.5, 0.5),
+                &na::Vector3::new(0., 0., 0.)
+            ),
+            Some((-f64::INFINITY, f64::INFINITY))
+        );
+        assert_eq!(
+            bbox.intersect_ray(
+                &na::Point3::new(2., 0.5, 0.5),
+                &na::Vector3::new(0., 0., 0.)
+            ),
+            None
+        );
+        assert_eq!(
+            BoundingBox::<f64>::neg_infinity().intersect_ray(
+                &na::Point3::new(-1., 0.5, 0.5),
+                &na::Vector3::new(1., 0., 0.)
+            ),
+            None
+        );
+        let disjoint = bbox.intersection(&BoundingBox::<f64>::new(
+            &na::Point3::new(2., 0., 0.),
+            &na::Point3::new(3., 1., 1.),
+        ));
+        assert_eq!(
+            disjoint.intersect_ray(
+                &na::Point3::new(-1., 0.5, 0.5),
+                &na::Vector3::new(1., 0., 0.)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn closest_point() {
+        let bbox =
+            BoundingBox::<f64>::new(&na::Point3::new(0., 0., 0.), &na::Point3::new(1., 2., 3.));
+        assert_relative_eq!(
+            bbox.closest_point(&na::Point3::new(0.5, 1., 1.5)).unwrap(),
+            na::Point3::new(0.5, 1., 1.5)
+        );
+        assert_relative_eq!(
+            bbox.closest_point(&na::Point3::new(-1., 1., 4.)).unwrap(),
+            na::Point3::new(0., 1., 3.)
+        );
+        assert_relative_eq!(
+            bbox.closest_point(&na::Point3::new(2., 3., -1.)).unwrap(),
+            na::Point3::new(1., 2., 0.)
+        );
+        assert_eq!(
+            BoundingBox::<f64>::neg_infinity().closest_point(&na::Point3::new(0.5, 1., 1.5)),
+            None
+        );
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let mut bbox = BoundingBox::<f64>::new(
+            &na::Point3::new(-0.3, 0.2, 1.),
+            &na::Point3::new(0.7, 1.1, 1.5),
+        );
+        assert_relative_eq!(
+            bbox.snap_to_grid(0.5),
+            &mut BoundingBox::<f64>::new(
+                &na::Point3::new(-0.5, 0., 1.),
+                &na::Point3::new(1., 1.5, 1.5),
+            )
+        );
+        let snapped = bbox.clone();
+        assert_eq!(bbox.snap_to_grid(0.5), &snapped);
+    }
+
+    #[test]
+    fn snap_to_inexact_grid() {
+        let mut left =
+            BoundingBox::<f64>::new(&na::Point3::new(0., 0., 0.), &na::Point3::new(0.3, 1., 1.));
+        let mut right =
+            BoundingBox::<f64>::new(&na::Point3::new(0.3, 0., 0.), &na::Point3::new(0.6, 1., 1.));
+        left.snap_to_grid(0.1);
+        right.snap_to_grid(0.1);
+        assert_eq!(left.max.x, right.min.x);
+        assert_relative_eq!(left.max.x, 0.3);
+        assert_relative_eq!(right.max.x, 0.6);
+        let snapped = left.clone();
+        assert_eq!(left.snap_to_grid(0.1), &snapped);
+        let snapped = right.clone();
+        assert_eq!(right.snap_to_grid(0.1), &snapped);
+        // 3 * 0.1 rounds to slightly more than 0.3, moving this corner inward by rounding error.
+        assert!(right.min.x > 0.3);
+        assert_relative_eq!(right.min.x, 0.3);
+        for &spacing in &[0.1, 0.3, 0.7] {
+            for i in -20..20 {
+                let corner = na::Point3::new(f64::from(i) * 0.1, f64::from(i) * 0.3, 0.7);
+                let original = BoundingBox::<f64>::new(&corner, &(corner * 2.));
+                let mut snapped = original.clone();
+                snapped.snap_to_grid(spacing).dilate(1e-12);
+                assert!(snapped.contains(&original.min) && snapped.contains(&original.max));
+            }
+        }
+    }
 }